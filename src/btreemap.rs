@@ -1,14 +1,44 @@
 //! Implementation of [`Map`] for a [`BTreeMap`].
+use std::borrow::Borrow;
 use std::collections::BTreeMap;
 
-use crate::Map;
+use crate::{Map, MapQuery};
 
 impl<K, V, C> Map<K, V> for BTreeMap<K, C>
 where
-    K: Eq + Ord + std::hash::Hash,
+    K: Eq + Ord,
     C: Default + Extend<V>,
 {
+    type Collection = C;
+
     fn insert(&mut self, key: K, value: V) {
         self.entry(key).or_default().extend(std::iter::once(value));
     }
+
+    fn insert_many(&mut self, key: K, values: C)
+    where
+        K: Clone,
+        C: IntoIterator<Item = V>,
+    {
+        self.entry(key).or_default().extend(values);
+    }
+}
+
+impl<K, C, Q> MapQuery<K, Q> for BTreeMap<K, C>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Collection = C;
+
+    fn get_all(&self, key: &Q) -> Option<&C> {
+        BTreeMap::get(self, key)
+    }
+
+    fn value_count(&self) -> usize
+    where
+        for<'a> &'a C: IntoIterator,
+    {
+        self.values().map(|values| values.into_iter().count()).sum()
+    }
 }
@@ -1,15 +1,47 @@
 //! Implementation of [`Map`] for a [`HashMap`].
+use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
 
-use crate::Map;
+use crate::{Map, MapQuery};
 
 impl<K, V, C, S> Map<K, V> for HashMap<K, C, S>
 where
-    K: Eq + std::hash::Hash,
+    K: Eq + Hash,
     C: Default + Extend<V>,
-    S: std::hash::BuildHasher,
+    S: BuildHasher,
 {
+    type Collection = C;
+
     fn insert(&mut self, key: K, value: V) {
         self.entry(key).or_default().extend(std::iter::once(value));
     }
+
+    fn insert_many(&mut self, key: K, values: C)
+    where
+        K: Clone,
+        C: IntoIterator<Item = V>,
+    {
+        self.entry(key).or_default().extend(values);
+    }
+}
+
+impl<K, C, S, Q> MapQuery<K, Q> for HashMap<K, C, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    S: BuildHasher,
+    Q: Hash + Eq + ?Sized,
+{
+    type Collection = C;
+
+    fn get_all(&self, key: &Q) -> Option<&C> {
+        HashMap::get(self, key)
+    }
+
+    fn value_count(&self) -> usize
+    where
+        for<'a> &'a C: IntoIterator,
+    {
+        self.values().map(|values| values.into_iter().count()).sum()
+    }
 }
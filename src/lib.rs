@@ -77,6 +77,8 @@ use std::ops::{Deref, DerefMut};
 pub mod btreemap;
 #[cfg(feature = "hashmap")]
 pub mod hashmap;
+#[cfg(feature = "vecmap")]
+pub mod vecmap;
 
 /// A wrapper around a "map" type that lets you collect an iterator of key-value pairs into a
 /// mapping between keys and collections of values, instead of just keys to values.
@@ -89,6 +91,93 @@ impl<M> AggregateMap<M> {
     pub fn into_inner(self) -> M {
         self.0
     }
+
+    /// Returns the collection of all values aggregated under `key`, or [`None`] if no value has
+    /// been inserted for it, without having to unwrap to the concrete map type `M`.
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use aggregate_map::AggregateMap;
+    /// let collected: AggregateMap<HashMap<_, Vec<_>>> =
+    ///     [("dog", "Terry"), ("dog", "Zamboni")].into_iter().collect();
+    /// assert_eq!(collected.get_all("dog"), Some(&vec!["Terry", "Zamboni"]));
+    /// assert_eq!(collected.get_all("cat"), None);
+    /// ```
+    pub fn get_all<Q, K>(&self, key: &Q) -> Option<&M::Collection>
+    where
+        Q: ?Sized,
+        M: MapQuery<K, Q>,
+    {
+        self.0.get_all(key)
+    }
+
+    /// Returns `true` if at least one value has been aggregated under `key`.
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use aggregate_map::AggregateMap;
+    /// let collected: AggregateMap<HashMap<_, Vec<_>>> =
+    ///     [("dog", "Terry")].into_iter().collect();
+    /// assert!(collected.contains_key("dog"));
+    /// assert!(!collected.contains_key("cat"));
+    /// ```
+    pub fn contains_key<Q, K>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized,
+        M: MapQuery<K, Q>,
+    {
+        self.0.contains_key(key)
+    }
+
+    /// Returns the total number of values aggregated across all keys, as opposed to the number of
+    /// keys.
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use aggregate_map::AggregateMap;
+    /// let collected: AggregateMap<HashMap<_, Vec<_>>> =
+    ///     [("dog", "Terry"), ("dog", "Zamboni"), ("cat", "Jonathan")].into_iter().collect();
+    /// assert_eq!(collected.value_count(), 3);
+    /// ```
+    pub fn value_count<K>(&self) -> usize
+    where
+        M: MapQuery<K>,
+        for<'a> &'a M::Collection: IntoIterator,
+    {
+        self.0.value_count()
+    }
+
+    /// Merges `other` into `self`. For keys present in both maps, the two value-collections are
+    /// concatenated together instead of one overwriting the other; for keys only present in
+    /// `other`, the whole collection is moved over as-is.
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use aggregate_map::AggregateMap;
+    /// let mut collected: AggregateMap<HashMap<_, Vec<_>>> =
+    ///     [("dog", "Terry"), ("cat", "Jonathan")].into_iter().collect();
+    /// let more: AggregateMap<HashMap<_, Vec<_>>> =
+    ///     [("dog", "Zamboni"), ("bird", "Tweety")].into_iter().collect();
+    /// collected.merge(more);
+    /// assert_eq!(collected.get_all("dog"), Some(&vec!["Terry", "Zamboni"]));
+    /// assert_eq!(collected.get_all("bird"), Some(&vec!["Tweety"]));
+    /// ```
+    ///
+    /// There's deliberately no `impl Extend<(K, M::Collection)> for AggregateMap<M>` alongside
+    /// this method: it would conflict with the existing `impl Extend<(K, V)> for AggregateMap<M>`
+    /// below, since the compiler can't prove `V` and `M::Collection` are always distinct types for
+    /// a generic `M`, so the two blanket impls would overlap (`error[E0119]`). `merge` is the
+    /// supported way to fold a whole grouped map into another one.
+    pub fn merge<K, V>(&mut self, other: Self)
+    where
+        M: Map<K, V> + IntoIterator<Item = (K, M::Collection)>,
+        K: Clone,
+        M::Collection: IntoIterator<Item = V>,
+    {
+        for (key, values) in other.into_inner() {
+            self.0.insert_many(key, values);
+        }
+    }
 }
 impl<M> Deref for AggregateMap<M> {
     type Target = M;
@@ -119,8 +208,58 @@ impl<M> From<M> for AggregateMap<M> {
 /// (like [`Vec`] or [`HashSet`][std::collections::HashSet]), which contains multiple values of type
 /// `V`.
 pub trait Map<K, V> {
+    /// The collection type holding all the values aggregated under a single key.
+    type Collection;
+
     /// Insert one `value` into the collection contained at `key`.
     fn insert(&mut self, key: K, value: V);
+
+    /// Insert a whole collection of `values` under `key`, extending any collection already
+    /// present there instead of overwriting it.
+    ///
+    /// The default implementation falls back to inserting `values` one by one, which needs a
+    /// fresh `key` for every call to [`insert`][Self::insert]; implementors can override this to
+    /// fold `values` into the existing collection directly (e.g. via `entry(key).or_default()`)
+    /// without cloning `key`. The `K: Clone` bound is part of the method's signature, so it
+    /// applies to callers either way.
+    fn insert_many(&mut self, key: K, values: Self::Collection)
+    where
+        K: Clone,
+        Self::Collection: IntoIterator<Item = V>,
+    {
+        for value in values {
+            self.insert(key.clone(), value);
+        }
+    }
+}
+
+/// A trait for backend-agnostic *read* access into a [`Map`], kept separate from [`Map`] itself so
+/// that looking a value up never has to name the aggregated value type `V`.
+///
+/// Different backends need different bounds to look a key up by a borrowed form `Q` (e.g. `&str`
+/// to query a `String`-keyed map): [`HashMap`][std::collections::HashMap] needs `Q: Hash + Eq`,
+/// while [`BTreeMap`][std::collections::BTreeMap] needs `Q: Ord`. Rather than forcing every
+/// backend to satisfy the union of both (which would make a `HashMap` over a non-`Ord` key
+/// uncallable), `Q` is a type parameter of the trait itself, defaulting to `K`, so each
+/// implementation can declare only the bound it actually needs.
+pub trait MapQuery<K, Q: ?Sized = K> {
+    /// The collection type holding all the values aggregated under a single key.
+    type Collection;
+
+    /// Returns the collection of all values aggregated under `key`, or [`None`] if no value has
+    /// been inserted for it.
+    fn get_all(&self, key: &Q) -> Option<&Self::Collection>;
+
+    /// Returns `true` if at least one value has been aggregated under `key`.
+    fn contains_key(&self, key: &Q) -> bool {
+        self.get_all(key).is_some()
+    }
+
+    /// Returns the total number of values aggregated across all keys, as opposed to the number of
+    /// keys.
+    fn value_count(&self) -> usize
+    where
+        for<'a> &'a Self::Collection: IntoIterator;
 }
 
 impl<M, K, V> Extend<(K, V)> for AggregateMap<M>
@@ -143,3 +282,50 @@ where
         this
     }
 }
+
+/// Extension trait for iterators of key-value pairs, giving you a fluent `.aggregate()` instead
+/// of a type-annotated `.collect::<AggregateMap<_>>()`.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use aggregate_map::AggregateExt;
+/// let data = [("dog", "Terry"), ("dog", "Zamboni"), ("cat", "Jonathan")];
+/// let collected = data.into_iter().aggregate::<HashMap<_, Vec<_>>>();
+/// assert_eq!(collected.into_inner()["dog"], vec!["Terry", "Zamboni"]);
+/// ```
+pub trait AggregateExt: Iterator {
+    /// Collects `self` into an [`AggregateMap<M>`], mirroring [`Iterator::collect`] but fixed to
+    /// [`AggregateMap`] so the target map can be named directly at the call site, e.g.
+    /// `pairs.aggregate::<HashMap<_, Vec<_>>>()`.
+    fn aggregate<M>(self) -> AggregateMap<M>
+    where
+        Self: Sized,
+        AggregateMap<M>: FromIterator<Self::Item>,
+    {
+        self.collect()
+    }
+
+    /// Like [`aggregate`][Self::aggregate], but folds into a pre-existing (possibly pre-sized)
+    /// map `M` instead of starting from [`Default`].
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use aggregate_map::AggregateExt;
+    /// let mut existing = HashMap::with_capacity(2);
+    /// existing.insert("dog", vec!["Priscilla"]);
+    /// let data = [("dog", "Terry"), ("cat", "Jonathan")];
+    /// let collected = data.into_iter().aggregate_into(existing);
+    /// assert_eq!(collected.into_inner()["dog"], vec!["Priscilla", "Terry"]);
+    /// ```
+    fn aggregate_into<M>(self, map: M) -> AggregateMap<M>
+    where
+        Self: Sized,
+        AggregateMap<M>: Extend<Self::Item>,
+    {
+        let mut this = AggregateMap::from(map);
+        this.extend(self);
+        this
+    }
+}
+
+impl<I: Iterator> AggregateExt for I {}
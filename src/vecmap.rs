@@ -0,0 +1,99 @@
+//! A dense [`Map`] backed by a `Vec<Option<C>>`, indexed directly by a `usize` key.
+use crate::{Map, MapQuery};
+
+/// A [`Map`] implementation for small, dense integer keys (enum discriminants, slot indices, and
+/// the like), backed by a `Vec<Option<C>>` indexed directly by key instead of hashing or
+/// comparing it like [`HashMap`][std::collections::HashMap] or
+/// [`BTreeMap`][std::collections::BTreeMap] would.
+///
+/// Because the key is used as a direct index, inserting at key `n` grows the backing vector to
+/// length `n + 1`, leaving `None` in any unused slots below it. This makes `VecMap` a poor fit for
+/// sparse keys: inserting a single huge key (say, a million) will try to allocate a vector large
+/// enough to hold every index up to it. Inserting at `key == usize::MAX` specifically panics
+/// (there's no `usize` large enough to hold `usize::MAX + 1` slots) rather than silently
+/// discarding whatever was already in the map. Only use `VecMap` when your keys are known to be
+/// small and densely packed.
+///
+/// ```rust
+/// # use aggregate_map::AggregateMap;
+/// # use aggregate_map::vecmap::VecMap;
+/// let collected: AggregateMap<VecMap<Vec<_>>> =
+///     [(0, "Terry"), (2, "Jonathan"), (0, "Zamboni")].into_iter().collect();
+/// assert_eq!(collected.get_all(&0), Some(&vec!["Terry", "Zamboni"]));
+/// assert_eq!(collected.get_all(&1), None);
+/// assert_eq!(collected.value_count(), 3);
+/// ```
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct VecMap<C>(Vec<Option<C>>);
+
+impl<C> VecMap<C> {
+    /// Consumes the [`VecMap`] to give you the inner `Vec<Option<C>>`.
+    pub fn into_inner(self) -> Vec<Option<C>> {
+        self.0
+    }
+}
+
+impl<C> From<Vec<Option<C>>> for VecMap<C> {
+    fn from(inner: Vec<Option<C>>) -> Self {
+        Self(inner)
+    }
+}
+
+impl<V, C> Map<usize, V> for VecMap<C>
+where
+    C: Default + Extend<V>,
+{
+    type Collection = C;
+
+    fn insert(&mut self, key: usize, value: V) {
+        if key >= self.0.len() {
+            let len = key
+                .checked_add(1)
+                .expect("VecMap key must be less than usize::MAX");
+            self.0.resize_with(len, || None);
+        }
+        self.0[key]
+            .get_or_insert_with(C::default)
+            .extend(std::iter::once(value));
+    }
+}
+
+impl<C> MapQuery<usize> for VecMap<C> {
+    type Collection = C;
+
+    /// Looks `key` up by indexing directly into the backing vector, in `O(1)` — the whole reason
+    /// to reach for `VecMap` over [`HashMap`][std::collections::HashMap] in the first place.
+    fn get_all(&self, key: &usize) -> Option<&C> {
+        self.0.get(*key).and_then(Option::as_ref)
+    }
+
+    fn value_count(&self) -> usize
+    where
+        for<'a> &'a C: IntoIterator,
+    {
+        self.0
+            .iter()
+            .filter_map(|slot| slot.as_ref())
+            .map(|values| values.into_iter().count())
+            .sum()
+    }
+}
+
+impl<C> IntoIterator for VecMap<C> {
+    type Item = (usize, C);
+    type IntoIter = std::iter::FilterMap<
+        std::iter::Enumerate<std::vec::IntoIter<Option<C>>>,
+        fn((usize, Option<C>)) -> Option<(usize, C)>,
+    >;
+
+    /// Iterates over the occupied `(key, collection)` pairs, skipping any unfilled gaps in the
+    /// backing vector. This lets a `VecMap`-backed [`AggregateMap`][crate::AggregateMap] be
+    /// [`merge`][crate::AggregateMap::merge]d like any other backend.
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, slot)| slot.map(|value| (index, value)))
+    }
+}